@@ -1,13 +1,22 @@
 use core::any::{Any, TypeId};
 use core::fmt;
+use core::future::Future;
 use core::marker::PhantomData;
-use core::ops::Deref;
+use core::ops::{Deref, DerefMut};
 
 use std::collections::HashMap;
+use std::hash::Hash;
 use std::sync::Arc;
 
+#[cfg(not(feature = "may-runtime"))]
 use parking_lot::{RwLock, RwLockUpgradableReadGuard};
 
+// The `may` runtime's `RwLock` yields to the coroutine scheduler instead of
+// parking the OS thread, so it cooperates with stackful coroutines. It lacks
+// upgradable reads, so the fast path falls back to a write-lock double-check.
+#[cfg(feature = "may-runtime")]
+use may::sync::RwLock;
+
 /// ```
 /// use once_cell::sync::OnceCell;
 /// use generic_global_variables::*;
@@ -59,18 +68,80 @@ use parking_lot::{RwLock, RwLockUpgradableReadGuard};
 /// }
 /// ```
 #[derive(Default, Debug)]
-pub struct GenericGlobal(RwLock<HashMap<TypeId, Arc<dyn Any>>>);
+pub struct GenericGlobal(
+    RwLock<HashMap<TypeId, Arc<dyn Any>>>,
+    RwLock<KeyedMap>,
+);
+
+type Map = HashMap<TypeId, Arc<dyn Any>>;
+
+// Keyed entries are stored in a per-`(T, K)` inner map so the real key is kept
+// and compared by `Eq`, not merely hashed. The inner `Box<dyn Any>` downcasts
+// to `HashMap<K, Arc<dyn Any>>` for the `K` that produced the outer key.
+type KeyedMap = HashMap<(TypeId, TypeId), Box<dyn Any>>;
 
 impl GenericGlobal {
     pub fn new() -> Self {
         Self::default()
     }
 
-    pub fn get_or_init<T: 'static + Send + Sync>(&self, f: impl FnOnce() -> T) -> Entry<T> {
-        let typeid = TypeId::of::<T>();
+    // Backend-normalizing accessors: `parking_lot`'s guards are infallible
+    // while `may`'s mirror std and return a `LockResult`. `may` poisons on a
+    // panic-while-locked, so recover the guard from the poison error instead
+    // of propagating it; the store holds independent `Arc<dyn Any>` slots, so a
+    // panic in one initializer never corrupts the others, and this keeps the
+    // public API identical across backends.
+    #[cfg(not(feature = "may-runtime"))]
+    fn read_map(&self) -> impl Deref<Target = Map> + '_ {
+        self.0.read()
+    }
 
-        if let Some(val) = self.0.read().get(&typeid) {
-            return Entry::new(Arc::clone(val));
+    #[cfg(feature = "may-runtime")]
+    fn read_map(&self) -> impl Deref<Target = Map> + '_ {
+        self.0.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[cfg(not(feature = "may-runtime"))]
+    fn write_map(&self) -> impl DerefMut<Target = Map> + '_ {
+        self.0.write()
+    }
+
+    #[cfg(feature = "may-runtime")]
+    fn write_map(&self) -> impl DerefMut<Target = Map> + '_ {
+        self.0.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[cfg(not(feature = "may-runtime"))]
+    fn keyed_read_map(&self) -> impl Deref<Target = KeyedMap> + '_ {
+        self.1.read()
+    }
+
+    #[cfg(feature = "may-runtime")]
+    fn keyed_read_map(&self) -> impl Deref<Target = KeyedMap> + '_ {
+        self.1.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[cfg(not(feature = "may-runtime"))]
+    fn keyed_write_map(&self) -> impl DerefMut<Target = KeyedMap> + '_ {
+        self.1.write()
+    }
+
+    #[cfg(feature = "may-runtime")]
+    fn keyed_write_map(&self) -> impl DerefMut<Target = KeyedMap> + '_ {
+        self.1.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Double-checked insert into the type-keyed map: read-check, then on a
+    /// miss take an upgradable read (or, under `may-runtime`, a write lock),
+    /// check again, and insert the value produced by `f`. `f` is fallible so
+    /// that a failing initializer leaves the map untouched.
+    fn get_or_init_inner<E>(
+        &self,
+        typeid: TypeId,
+        f: impl FnOnce() -> Result<Arc<dyn Any>, E>,
+    ) -> Result<Arc<dyn Any>, E> {
+        if let Some(val) = self.read_map().get(&typeid) {
+            return Ok(Arc::clone(val));
         }
 
         // Use an upgradable_read to check if the key has already
@@ -80,23 +151,207 @@ impl GenericGlobal {
         // other UpgradableReadGuard and WriteGuard, so the readers
         // will not be blocked while ensuring that there is no other
         // writer.
+        #[cfg(not(feature = "may-runtime"))]
         let guard = self.0.upgradable_read();
 
+        // `may`'s RwLock has no upgradable read, so fall back to a write-lock
+        // double-check.
+        #[cfg(feature = "may-runtime")]
+        let mut guard = self.write_map();
+
         // If another writer has already added that typeid, return.
         if let Some(val) = guard.get(&typeid) {
-            return Entry::new(Arc::clone(val));
+            return Ok(Arc::clone(val));
         }
 
+        // Build the value before upgrading so that on `Err` the map is left
+        // untouched and no later call observes a partially initialized slot.
+        let arc = f()?;
+
         // If no other writer has added that typeid, add one now.
+        #[cfg(not(feature = "may-runtime"))]
         let mut guard = RwLockUpgradableReadGuard::upgrade(guard);
-        let arc: Arc<dyn Any> = Arc::new(f());
         let option = guard.insert(typeid, Arc::clone(&arc));
 
         // There cannot be any other write that insert the key.
         debug_assert!(option.is_none());
 
+        Ok(arc)
+    }
+
+    pub fn get_or_init<T: 'static + Send + Sync>(&self, f: impl FnOnce() -> T) -> Entry<T> {
+        let arc = self
+            .get_or_init_inner(TypeId::of::<T>(), || {
+                let val: Arc<dyn Any> = Arc::new(f());
+                Ok::<_, core::convert::Infallible>(val)
+            })
+            .unwrap();
+
+        Entry::new(arc)
+    }
+
+    /// Like [`GenericGlobal::get_or_init`], but the initializer returns a
+    /// [`Future`] that is awaited to produce the value.
+    ///
+    /// The future is never awaited while a lock guard is held: on a miss we
+    /// drop the read lock, await `f()`, then re-acquire the write lock and
+    /// check again. If another task inserted the same type while we were
+    /// awaiting, the freshly built value is discarded and the existing
+    /// `Entry` is returned. This means two tasks can redundantly build a
+    /// value under contention, but the map stays consistent and no executor
+    /// thread is ever blocked across an await.
+    pub async fn get_or_init_async<T, Fut>(&self, f: impl FnOnce() -> Fut) -> Entry<T>
+    where
+        T: 'static + Send + Sync,
+        Fut: Future<Output = T>,
+    {
+        let typeid = TypeId::of::<T>();
+
+        // Fast path: take a read lock, check for the type and drop the guard
+        // before doing anything that might await.
+        if let Some(val) = self.read_map().get(&typeid) {
+            return Entry::new(Arc::clone(val));
+        }
+
+        // Build the value with no guard held so the executor thread stays free
+        // across the await.
+        let arc: Arc<dyn Any> = Arc::new(f().await);
+
+        // Re-acquire the write lock and check again: another task may have
+        // inserted the same type while we were awaiting, in which case we
+        // discard our value and return theirs.
+        let mut guard = self.write_map();
+
+        if let Some(val) = guard.get(&typeid) {
+            return Entry::new(Arc::clone(val));
+        }
+
+        let option = guard.insert(typeid, Arc::clone(&arc));
+        debug_assert!(option.is_none());
+
         Entry::new(arc)
     }
+
+    /// Like [`GenericGlobal::get_or_init`], but the initializer is fallible.
+    ///
+    /// On a read-hit the stored [`Entry`] is returned without calling `f`.
+    /// On a miss `f` is called under the upgradable read (a write lock under
+    /// `may-runtime`): if it returns `Err` the lock is released leaving the map
+    /// untouched, so a later call can retry; if it returns `Ok` the lock is
+    /// upgraded, the value is inserted and the `Entry` is returned. A failed init never populates the global slot.
+    pub fn get_or_try_init<T: 'static + Send + Sync, E>(
+        &self,
+        f: impl FnOnce() -> Result<T, E>,
+    ) -> Result<Entry<T>, E> {
+        let arc = self.get_or_init_inner(TypeId::of::<T>(), || {
+            let val: Arc<dyn Any> = Arc::new(f()?);
+            Ok(val)
+        })?;
+
+        Ok(Entry::new(arc))
+    }
+
+    /// Like [`GenericGlobal::get_or_init`], but the value is stored behind its
+    /// own lock and reached through [`RwEntry`], giving a first-class
+    /// interior-mutable global keyed by type.
+    ///
+    /// Use [`RwEntry::read`] for shared access and [`RwEntry::write`] for
+    /// exclusive access; the returned guards borrow the value directly and are
+    /// independent of the outer map lock, which is released before they are
+    /// acquired.
+    pub fn get_or_init_rw<T: 'static + Send + Sync>(
+        &self,
+        f: impl FnOnce() -> T,
+    ) -> RwEntry<T> {
+        let entry = self.get_or_init(|| RwLock::new(f()));
+        RwEntry::new(Arc::clone(&entry.0))
+    }
+
+    /// Like [`GenericGlobal::get_or_init`], but several instances of the same
+    /// type are distinguished by a runtime `key` (e.g. a device name or
+    /// tenant id).
+    ///
+    /// The keyed store keeps a separate inner map per `(T, K)`, so keys are
+    /// compared by `Eq` and each `(T, key)` pair is initialized independently;
+    /// distinct keys never alias, even if their hashes collide.
+    pub fn get_or_init_keyed<K: Eq + Hash + 'static, T: 'static + Send + Sync>(
+        &self,
+        key: K,
+        f: impl FnOnce() -> T,
+    ) -> Entry<T> {
+        let outerkey = (TypeId::of::<T>(), TypeId::of::<K>());
+
+        // Fast path: look the key up in the inner map under a read lock.
+        if let Some(inner) = self.keyed_read_map().get(&outerkey) {
+            let inner = inner.downcast_ref::<HashMap<K, Arc<dyn Any>>>().unwrap();
+            if let Some(val) = inner.get(&key) {
+                return Entry::new(Arc::clone(val));
+            }
+        }
+
+        // Miss: use an upgradable_read (like `get_or_init_inner`) so readers of
+        // other keys are not blocked while we build the value, upgrading to a
+        // write guard only for the insert.
+        #[cfg(not(feature = "may-runtime"))]
+        let guard = self.1.upgradable_read();
+
+        // `may`'s RwLock has no upgradable read, so fall back to a write-lock
+        // double-check.
+        #[cfg(feature = "may-runtime")]
+        let mut guard = self.keyed_write_map();
+
+        // Re-check under the upgradable/write guard before building.
+        if let Some(inner) = guard
+            .get(&outerkey)
+            .and_then(|inner| inner.downcast_ref::<HashMap<K, Arc<dyn Any>>>())
+        {
+            if let Some(val) = inner.get(&key) {
+                return Entry::new(Arc::clone(val));
+            }
+        }
+
+        let arc: Arc<dyn Any> = Arc::new(f());
+
+        #[cfg(not(feature = "may-runtime"))]
+        let mut guard = RwLockUpgradableReadGuard::upgrade(guard);
+        let inner = guard
+            .entry(outerkey)
+            .or_insert_with(|| Box::new(HashMap::<K, Arc<dyn Any>>::new()))
+            .downcast_mut::<HashMap<K, Arc<dyn Any>>>()
+            .unwrap();
+        inner.insert(key, Arc::clone(&arc));
+
+        Entry::new(arc)
+    }
+
+    /// Return the stored [`Entry`] for `T` if one has been created, without
+    /// initializing it.
+    pub fn get<T: 'static + Send + Sync>(&self) -> Option<Entry<T>> {
+        self.read_map()
+            .get(&TypeId::of::<T>())
+            .map(|val| Entry::new(Arc::clone(val)))
+    }
+
+    /// Return whether a value for `T` has been created.
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.read_map().contains_key(&TypeId::of::<T>())
+    }
+
+    /// Remove the value for `T`, returning its [`Entry`] if present.
+    ///
+    /// The entry is `Arc`-backed, so outstanding clones keep the value alive
+    /// after it is removed from the store.
+    pub fn remove<T: 'static + Send + Sync>(&self) -> Option<Entry<T>> {
+        self.write_map()
+            .remove(&TypeId::of::<T>())
+            .map(Entry::new)
+    }
+
+    /// Remove every stored value, both type-keyed and `(type, key)`-keyed.
+    pub fn clear(&self) {
+        self.write_map().clear();
+        self.keyed_write_map().clear();
+    }
 }
 
 unsafe impl Send for GenericGlobal {}
@@ -141,5 +396,332 @@ impl<T: 'static> fmt::Pointer for Entry<T> {
     }
 }
 
+/// Whether a guard was taken for shared or exclusive access.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LockKind {
+    Shared,
+    Exclusive,
+}
+
+/// A reference to an interior-mutable entry created by
+/// [`GenericGlobal::get_or_init_rw`].
+///
+/// The value lives behind its own `RwLock`; call [`RwEntry::read`] or
+/// [`RwEntry::write`] to obtain a guard.
+#[derive(Debug)]
+pub struct RwEntry<T: 'static>(Arc<dyn Any>, PhantomData<T>);
+
+unsafe impl<T: 'static + Send + Sync> Send for RwEntry<T> {}
+unsafe impl<T: 'static + Send + Sync> Sync for RwEntry<T> {}
+
+impl<T: 'static> Clone for RwEntry<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<T: 'static> RwEntry<T> {
+    fn new(arc: Arc<dyn Any>) -> Self {
+        Self(arc, PhantomData)
+    }
+
+    fn lock(&self) -> &RwLock<T> {
+        <dyn Any>::downcast_ref::<RwLock<T>>(&*self.0).unwrap()
+    }
+
+    /// Acquire shared (read) access to the value.
+    pub fn read(&self) -> SharedGuard<'_, T> {
+        #[cfg(not(feature = "may-runtime"))]
+        let guard = self.lock().read();
+        #[cfg(feature = "may-runtime")]
+        let guard = self.lock().read().unwrap();
+
+        SharedGuard(guard)
+    }
+
+    /// Acquire exclusive (write) access to the value.
+    pub fn write(&self) -> ExclusiveGuard<'_, T> {
+        #[cfg(not(feature = "may-runtime"))]
+        let guard = self.lock().write();
+        #[cfg(feature = "may-runtime")]
+        let guard = self.lock().write().unwrap();
+
+        ExclusiveGuard(guard)
+    }
+}
+
+#[cfg(not(feature = "may-runtime"))]
+type InnerReadGuard<'a, T> = parking_lot::RwLockReadGuard<'a, T>;
+#[cfg(feature = "may-runtime")]
+type InnerReadGuard<'a, T> = may::sync::RwLockReadGuard<'a, T>;
+
+#[cfg(not(feature = "may-runtime"))]
+type InnerWriteGuard<'a, T> = parking_lot::RwLockWriteGuard<'a, T>;
+#[cfg(feature = "may-runtime")]
+type InnerWriteGuard<'a, T> = may::sync::RwLockWriteGuard<'a, T>;
+
+/// A shared guard into an [`RwEntry`], `Deref`-ing to the value.
+pub struct SharedGuard<'a, T>(InnerReadGuard<'a, T>);
+
+impl<T> SharedGuard<'_, T> {
+    /// The kind of lock this guard holds.
+    pub fn kind(&self) -> LockKind {
+        LockKind::Shared
+    }
+}
+
+impl<T> Deref for SharedGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// An exclusive guard into an [`RwEntry`], `Deref`/`DerefMut`-ing to the value.
+pub struct ExclusiveGuard<'a, T>(InnerWriteGuard<'a, T>);
+
+impl<T> ExclusiveGuard<'_, T> {
+    /// The kind of lock this guard holds.
+    pub fn kind(&self) -> LockKind {
+        LockKind::Exclusive
+    }
+}
+
+impl<T> Deref for ExclusiveGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for ExclusiveGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    // Minimal executor so the async tests don't pull in a runtime dependency.
+    // The futures under test only await an immediately-ready value, so a busy
+    // poll with a no-op waker is enough.
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        use core::pin::Pin;
+        use core::ptr;
+        use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(ptr::null(), &VTABLE)
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+        let waker = unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) };
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = fut;
+        // Safety: `fut` is not moved again before it is dropped.
+        let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+
+        loop {
+            if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+                return out;
+            }
+        }
+    }
+
+    #[test]
+    fn get_or_init_async_initializes_and_caches() {
+        let globals = GenericGlobal::new();
+
+        let first = block_on(globals.get_or_init_async(|| async { 42u32 }));
+        assert_eq!(*first, 42);
+
+        // Second call must return the cached value, not run the initializer.
+        let second = block_on(globals.get_or_init_async::<u32, _>(|| async {
+            panic!("initializer must not run on a hit")
+        }));
+        assert_eq!(*second, 42);
+        assert!(std::ptr::eq(&*first, &*second));
+    }
+
+    #[test]
+    fn get_or_init_async_discards_redundant_build_on_race() {
+        let globals = GenericGlobal::new();
+
+        // Simulate the "another task inserted while we were awaiting" path:
+        // the slot is populated from inside the pending future, after the
+        // read-check missed, so the awaited build must be discarded and the
+        // existing entry returned.
+        let g = &globals;
+        let awaited = block_on(globals.get_or_init_async(move || async move {
+            g.get_or_init(|| 7u32);
+            999u32
+        }));
+        let existing = globals.get::<u32>().unwrap();
+
+        assert_eq!(*awaited, 7);
+        assert!(std::ptr::eq(&*existing, &*awaited));
+    }
+
+    #[test]
+    fn get_or_init_is_consistent_across_threads() {
+        let globals = Arc::new(GenericGlobal::new());
+
+        let handles: Vec<_> = (0..16)
+            .map(|_| {
+                let globals = Arc::clone(&globals);
+                std::thread::spawn(move || {
+                    let entry = globals.get_or_init(|| String::from("shared"));
+                    (&*entry) as *const String as usize
+                })
+            })
+            .collect();
+
+        let addrs: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+        // Every thread must observe the exact same instance.
+        assert!(addrs.iter().all(|&a| a == addrs[0]));
+    }
+
+    #[test]
+    fn panicking_initializer_leaves_store_usable() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let globals = GenericGlobal::new();
+
+        let panicked = catch_unwind(AssertUnwindSafe(|| {
+            globals.get_or_init::<u32>(|| panic!("boom"));
+        }));
+        assert!(panicked.is_err());
+
+        // The lock must not be poisoned: the store stays fully usable across
+        // backends, including the `may-runtime` lock which poisons on panic.
+        assert!(!globals.contains::<u32>());
+        let entry = globals.get_or_init(|| 5u32);
+        assert_eq!(*entry, 5);
+
+        let keyed_panicked = catch_unwind(AssertUnwindSafe(|| {
+            globals.get_or_init_keyed("dev", || -> u64 { panic!("boom") });
+        }));
+        assert!(keyed_panicked.is_err());
+        assert_eq!(*globals.get_or_init_keyed("dev", || 9u64), 9);
+    }
+
+    #[test]
+    fn get_or_try_init_error_leaves_slot_untouched() {
+        let globals = GenericGlobal::new();
+
+        let err = globals.get_or_try_init::<u32, &str>(|| Err("boom"));
+        assert!(matches!(err, Err("boom")));
+        assert!(!globals.contains::<u32>());
+
+        // A later call can still succeed; the failed init did not poison the slot.
+        let ok = globals.get_or_try_init::<u32, &str>(|| Ok(5)).unwrap();
+        assert_eq!(*ok, 5);
+
+        // Once populated, `f` is not called again.
+        let again = globals
+            .get_or_try_init::<u32, &str>(|| panic!("must not run on a hit"))
+            .unwrap();
+        assert_eq!(*again, 5);
+    }
+
+    #[test]
+    fn get_or_init_rw_allows_shared_and_exclusive_access() {
+        let globals = GenericGlobal::new();
+
+        let entry = globals.get_or_init_rw(|| vec![1, 2, 3]);
+
+        {
+            let guard = entry.read();
+            assert_eq!(guard.kind(), LockKind::Shared);
+            assert_eq!(&*guard, &[1, 2, 3]);
+        }
+
+        {
+            let mut guard = entry.write();
+            assert_eq!(guard.kind(), LockKind::Exclusive);
+            guard.push(4);
+        }
+
+        // Mutation through one handle is visible through another.
+        let other = globals.get_or_init_rw::<Vec<i32>>(Vec::new);
+        assert_eq!(&*other.read(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn get_or_init_keyed_distinguishes_keys() {
+        let globals = GenericGlobal::new();
+
+        let a = globals.get_or_init_keyed("dev-a".to_string(), || 1u32);
+        let b = globals.get_or_init_keyed("dev-b".to_string(), || 2u32);
+
+        // Distinct keys get independently initialized instances.
+        assert_eq!(*a, 1);
+        assert_eq!(*b, 2);
+        assert!(!std::ptr::eq(&*a, &*b));
+
+        // An equal key (distinct allocation) maps to the same instance and does
+        // not re-run the initializer — identity is by `Eq`, not by pointer.
+        let a_again = globals.get_or_init_keyed("dev-a".to_string(), || panic!("hit"));
+        assert_eq!(*a_again, 1);
+        assert!(std::ptr::eq(&*a, &*a_again));
+    }
+
+    // Regression: two keys must never alias even when their hashes collide.
+    // `Collide` hashes every value identically, so a hash-only store would
+    // return the first instance for the second key.
+    #[derive(PartialEq, Eq)]
+    struct Collide(u32);
+
+    impl std::hash::Hash for Collide {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            0u8.hash(state);
+        }
+    }
+
+    #[test]
+    fn get_or_init_keyed_does_not_alias_on_hash_collision() {
+        let globals = GenericGlobal::new();
+
+        let a = globals.get_or_init_keyed(Collide(1), || 10u32);
+        let b = globals.get_or_init_keyed(Collide(2), || 20u32);
+
+        assert_eq!(*a, 10);
+        assert_eq!(*b, 20);
+    }
+
+    #[test]
+    fn get_contains_remove_clear_round_trip() {
+        let globals = GenericGlobal::new();
+
+        assert!(globals.get::<u32>().is_none());
+        assert!(!globals.contains::<u32>());
+
+        globals.get_or_init(|| 1u32);
+        assert!(globals.contains::<u32>());
+        assert_eq!(*globals.get::<u32>().unwrap(), 1);
+
+        // `remove` returns the entry and outstanding clones keep it alive.
+        let removed = globals.remove::<u32>().unwrap();
+        assert_eq!(*removed, 1);
+        assert!(!globals.contains::<u32>());
+        assert!(globals.remove::<u32>().is_none());
+
+        // `clear` drops both type-keyed and `(type, key)`-keyed entries.
+        globals.get_or_init(|| 2u32);
+        globals.get_or_init_keyed("k".to_string(), || 3u64);
+        globals.clear();
+        assert!(!globals.contains::<u32>());
+
+        // A cleared keyed slot is re-initialized rather than returning the old value.
+        let fresh = globals.get_or_init_keyed("k".to_string(), || 4u64);
+        assert_eq!(*fresh, 4);
+    }
+}